@@ -0,0 +1,79 @@
+//! Optional conversions from this crate's [`datetime`](crate::datetime)
+//! types into [`chrono`] calendar types, enabled by the `chrono` feature.
+//!
+//! `Time::seconds` carries whole and fractional seconds together (e.g.
+//! `4.049`), which this module splits into whole seconds plus nanoseconds
+//! for `chrono`'s API.
+
+use core::convert::TryFrom;
+
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+use crate::datetime::{DateTime, Time};
+
+fn split_seconds(seconds: f32) -> Result<(u32, u32), &'static str> {
+    if !seconds.is_finite() || seconds < 0.0 {
+        return Err("Time field has an invalid seconds value!");
+    }
+    let whole = seconds as u32;
+    let nanos = libm::roundf((seconds - whole as f32) * 1_000_000_000.0) as u32;
+    Ok((whole, nanos))
+}
+
+impl From<Time> for NaiveTime {
+    /// Converts to a [`NaiveTime`], rounding the fractional seconds field to
+    /// the nearest nanosecond.
+    fn from(time: Time) -> NaiveTime {
+        let (whole_seconds, nanos) = split_seconds(time.seconds).unwrap_or((0, 0));
+        NaiveTime::from_hms_nano_opt(
+            time.hours as u32,
+            time.minutes as u32,
+            whole_seconds,
+            nanos,
+        )
+        .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap())
+    }
+}
+
+impl TryFrom<DateTime> for NaiveDateTime {
+    type Error = &'static str;
+
+    /// Converts to a [`NaiveDateTime`], still expressed in UTC (no
+    /// timezone/offset applied - use [`to_fixed_offset_datetime`] for that).
+    fn try_from(datetime: DateTime) -> Result<NaiveDateTime, &'static str> {
+        let date = NaiveDate::from_ymd_opt(
+            datetime.date.year as i32,
+            datetime.date.month as u32,
+            datetime.date.day as u32,
+        )
+        .ok_or("Date field is out of range!")?;
+        let (whole_seconds, nanos) = split_seconds(datetime.time.seconds)?;
+        let time = NaiveTime::from_hms_nano_opt(
+            datetime.time.hours as u32,
+            datetime.time.minutes as u32,
+            whole_seconds,
+            nanos,
+        )
+        .ok_or("Time field is out of range!")?;
+        Ok(NaiveDateTime::new(date, time))
+    }
+}
+
+/// Applies a ZDA `offset_hours`/`offset_minutes` pair to a UTC
+/// [`DateTime`], producing a fixed-offset local datetime.
+pub fn to_fixed_offset_datetime(
+    datetime: DateTime,
+    offset_hours: i8,
+    offset_minutes: u8,
+) -> Result<chrono::DateTime<FixedOffset>, &'static str> {
+    let naive = NaiveDateTime::try_from(datetime)?;
+    // `offset_minutes` carries no sign of its own in ZDA - it follows
+    // `offset_hours`'s sign, defaulting to positive for a `00` hours field
+    // (e.g. `+00:30`). `i8::signum` would zero this out whenever
+    // `offset_hours == 0`, silently dropping the minutes.
+    let sign: i32 = if offset_hours < 0 { -1 } else { 1 };
+    let offset_seconds = offset_hours as i32 * 3600 + sign * offset_minutes as i32 * 60;
+    let offset =
+        FixedOffset::east_opt(offset_seconds).ok_or("ZDA UTC offset is out of range!")?;
+    Ok(offset.from_utc_datetime(&naive))
+}
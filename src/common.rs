@@ -0,0 +1,38 @@
+//! Small helpers shared by the sentence parsers for turning a comma-separated
+//! field into a typed value, treating both a missing field and an empty
+//! field as "no value" rather than a parse error.
+
+pub(crate) fn parse_u8(field: Option<&str>) -> Result<Option<u8>, &'static str> {
+    match field {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse::<u8>().map(Some).map_err(|_| "Failed to parse u8 field!"),
+    }
+}
+
+pub(crate) fn parse_u16(field: Option<&str>) -> Result<Option<u16>, &'static str> {
+    match field {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse::<u16>().map(Some).map_err(|_| "Failed to parse u16 field!"),
+    }
+}
+
+pub(crate) fn parse_i8(field: Option<&str>) -> Result<Option<i8>, &'static str> {
+    match field {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse::<i8>().map(Some).map_err(|_| "Failed to parse i8 field!"),
+    }
+}
+
+pub(crate) fn parse_f32(field: Option<&str>) -> Result<Option<f32>, &'static str> {
+    match field {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse::<f32>().map(Some).map_err(|_| "Failed to parse f32 field!"),
+    }
+}
+
+pub(crate) fn parse_f64(field: Option<&str>) -> Result<Option<f64>, &'static str> {
+    match field {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse::<f64>().map(Some).map_err(|_| "Failed to parse f64 field!"),
+    }
+}
@@ -0,0 +1,227 @@
+//! Geographic position and motion types shared by the positioning sentences
+//! (RMC, GGA, GLL, VTG).
+
+use core::convert::TryFrom;
+
+/// Which side of the equator/prime meridian a coordinate falls on.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Hemisphere {
+    North,
+    South,
+    East,
+    West,
+}
+
+/// Latitude as degrees, minutes and seconds plus hemisphere, matching the
+/// NMEA `ddmm.mmmm,H` wire format.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Latitude {
+    pub degrees: u8,
+    pub minutes: u8,
+    pub seconds: f64,
+    pub hemisphere: Hemisphere,
+}
+
+/// Longitude as degrees, minutes and seconds plus hemisphere, matching the
+/// NMEA `dddmm.mmmm,H` wire format.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Longitude {
+    pub degrees: u8,
+    pub minutes: u8,
+    pub seconds: f64,
+    pub hemisphere: Hemisphere,
+}
+
+/// A course/bearing in degrees (true or magnetic), as reported by RMC/VTG's
+/// `course` and `magnetic` fields.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Course {
+    degrees: f32,
+}
+
+impl Course {
+    pub fn degrees(self) -> f32 {
+        self.degrees
+    }
+}
+
+impl From<f32> for Course {
+    fn from(degrees: f32) -> Course {
+        Course { degrees }
+    }
+}
+
+/// Speed over ground, stored in knots as reported by RMC/VTG.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Speed {
+    knots: f32,
+}
+
+impl Speed {
+    pub fn from_knots(knots: f32) -> Speed {
+        Speed { knots }
+    }
+
+    pub fn as_knots(&self) -> f32 {
+        self.knots
+    }
+
+    pub fn as_kph(&self) -> f32 {
+        self.knots * 1.852
+    }
+}
+
+/// Altitude above mean sea level, in meters, as reported by GGA.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Altitude {
+    pub meters: f64,
+}
+
+fn split_degrees_minutes(raw: &str, degree_digits: usize) -> Result<(u8, f64), &'static str> {
+    // The checksum is a plain XOR fold, so a corrupted-but-valid sentence can
+    // still carry non-ASCII bytes here; byte-index slicing a `&str` panics if
+    // that lands a multi-byte character across the split point, so reject
+    // non-ASCII input before slicing rather than trusting `raw.len()`.
+    if !raw.is_ascii() {
+        return Err("Coordinate field is not ASCII!");
+    }
+    if raw.len() < degree_digits {
+        return Err("Coordinate field is too short!");
+    }
+    let degrees = raw[..degree_digits]
+        .parse::<u8>()
+        .map_err(|_| "Failed to parse coordinate degrees!")?;
+    let minutes = raw[degree_digits..]
+        .parse::<f64>()
+        .map_err(|_| "Failed to parse coordinate minutes!")?;
+    Ok((degrees, minutes))
+}
+
+fn decompose_degrees(absolute: f64) -> (u8, u8, f64) {
+    let degrees = absolute as u8;
+    let minutes_decimal = (absolute - degrees as f64) * 60.0;
+    let minutes = minutes_decimal as u8;
+    let seconds = (minutes_decimal - minutes as f64) * 60.0;
+    (degrees, minutes, seconds)
+}
+
+impl Latitude {
+    /// Parses a raw `ddmm.mmmm` field together with its `N`/`S` hemisphere
+    /// field, as used by RMC/GGA/GLL.
+    pub(crate) fn parse(
+        raw_latitude: Option<&str>,
+        raw_hemisphere: Option<&str>,
+    ) -> Result<Option<Latitude>, &'static str> {
+        match (raw_latitude, raw_hemisphere) {
+            (None, _) | (Some(""), _) => Ok(None),
+            (Some(raw_latitude), raw_hemisphere) => {
+                let (degrees, minutes) = split_degrees_minutes(raw_latitude, 2)?;
+                let hemisphere = match raw_hemisphere {
+                    Some("N") => Hemisphere::North,
+                    Some("S") => Hemisphere::South,
+                    _ => return Err("Unknown latitude hemisphere!"),
+                };
+                Ok(Some(Latitude {
+                    degrees,
+                    minutes: minutes as u8,
+                    seconds: (minutes - (minutes as u8) as f64) * 60.0,
+                    hemisphere,
+                }))
+            }
+        }
+    }
+}
+
+impl Longitude {
+    /// Parses a raw `dddmm.mmmm` field together with its `E`/`W` hemisphere
+    /// field, as used by RMC/GGA/GLL.
+    pub(crate) fn parse(
+        raw_longitude: Option<&str>,
+        raw_hemisphere: Option<&str>,
+    ) -> Result<Option<Longitude>, &'static str> {
+        match (raw_longitude, raw_hemisphere) {
+            (None, _) | (Some(""), _) => Ok(None),
+            (Some(raw_longitude), raw_hemisphere) => {
+                let (degrees, minutes) = split_degrees_minutes(raw_longitude, 3)?;
+                let hemisphere = match raw_hemisphere {
+                    Some("E") => Hemisphere::East,
+                    Some("W") => Hemisphere::West,
+                    _ => return Err("Unknown longitude hemisphere!"),
+                };
+                Ok(Some(Longitude {
+                    degrees,
+                    minutes: minutes as u8,
+                    seconds: (minutes - (minutes as u8) as f64) * 60.0,
+                    hemisphere,
+                }))
+            }
+        }
+    }
+}
+
+impl TryFrom<f64> for Latitude {
+    type Error = &'static str;
+
+    fn try_from(decimal_degrees: f64) -> Result<Latitude, &'static str> {
+        if !(-90.0..=90.0).contains(&decimal_degrees) {
+            return Err("Latitude is out of range!");
+        }
+        let hemisphere = if decimal_degrees < 0.0 {
+            Hemisphere::South
+        } else {
+            Hemisphere::North
+        };
+        let (degrees, minutes, seconds) = decompose_degrees(decimal_degrees.abs());
+        Ok(Latitude {
+            degrees,
+            minutes,
+            seconds,
+            hemisphere,
+        })
+    }
+}
+
+impl TryFrom<f64> for Longitude {
+    type Error = &'static str;
+
+    fn try_from(decimal_degrees: f64) -> Result<Longitude, &'static str> {
+        if !(-180.0..=180.0).contains(&decimal_degrees) {
+            return Err("Longitude is out of range!");
+        }
+        let hemisphere = if decimal_degrees < 0.0 {
+            Hemisphere::West
+        } else {
+            Hemisphere::East
+        };
+        let (degrees, minutes, seconds) = decompose_degrees(decimal_degrees.abs());
+        Ok(Longitude {
+            degrees,
+            minutes,
+            seconds,
+            hemisphere,
+        })
+    }
+}
+
+impl From<Latitude> for f64 {
+    fn from(latitude: Latitude) -> f64 {
+        let magnitude =
+            latitude.degrees as f64 + latitude.minutes as f64 / 60.0 + latitude.seconds / 3600.0;
+        match latitude.hemisphere {
+            Hemisphere::South => -magnitude,
+            _ => magnitude,
+        }
+    }
+}
+
+impl From<Longitude> for f64 {
+    fn from(longitude: Longitude) -> f64 {
+        let magnitude = longitude.degrees as f64
+            + longitude.minutes as f64 / 60.0
+            + longitude.seconds / 3600.0;
+        match longitude.hemisphere {
+            Hemisphere::West => -magnitude,
+            _ => magnitude,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! UTC date and time types shared by the sentences that carry a fix epoch
+//! (RMC, GGA, GLL, ZDA).
+
+/// Calendar date in UTC, as carried by RMC (`ddmmyy`) or ZDA (`dd,mm,yyyy`).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Date {
+    pub day: u8,
+    pub month: u8,
+    pub year: u16,
+}
+
+/// Time of day in UTC, with fractional seconds as reported by the receiver.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Time {
+    pub hours: u8,
+    pub minutes: u8,
+    pub seconds: f32,
+}
+
+/// Combined UTC date and time, as reconstructed from an RMC sentence.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DateTime {
+    pub date: Date,
+    pub time: Time,
+}
+
+impl Time {
+    /// Parses the `hhmmss.sss` time field shared by RMC/GGA/GLL/ZDA.
+    pub(crate) fn parse_from_hhmmss(field: Option<&str>) -> Result<Option<Time>, &'static str> {
+        match field {
+            None | Some("") => Ok(None),
+            Some(s) if s.len() >= 6 => {
+                let hours = s[0..2]
+                    .parse::<u8>()
+                    .map_err(|_| "Failed to parse hours in time field!")?;
+                let minutes = s[2..4]
+                    .parse::<u8>()
+                    .map_err(|_| "Failed to parse minutes in time field!")?;
+                let seconds = s[4..]
+                    .parse::<f32>()
+                    .map_err(|_| "Failed to parse seconds in time field!")?;
+                Ok(Some(Time {
+                    hours,
+                    minutes,
+                    seconds,
+                }))
+            }
+            Some(_) => Err("Time field is too short!"),
+        }
+    }
+}
+
+impl Date {
+    /// Parses the `ddmmyy` date field used by RMC.
+    pub(crate) fn parse_from_ddmmyy(field: Option<&str>) -> Result<Option<Date>, &'static str> {
+        match field {
+            None | Some("") => Ok(None),
+            Some(s) if s.len() == 6 => {
+                let day = s[0..2]
+                    .parse::<u8>()
+                    .map_err(|_| "Failed to parse day in date field!")?;
+                let month = s[2..4]
+                    .parse::<u8>()
+                    .map_err(|_| "Failed to parse month in date field!")?;
+                let two_digit_year = s[4..6]
+                    .parse::<u16>()
+                    .map_err(|_| "Failed to parse year in date field!")?;
+                Ok(Some(Date {
+                    day,
+                    month,
+                    year: 2000 + two_digit_year,
+                }))
+            }
+            Some(_) => Err("Date field has unexpected length!"),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Re-serializes parsed sentences back into `$...*HH\r\n` wire format, for
+//! embedded users that also need to drive a GPS module (e.g. replaying a
+//! `VTG`/`RMC` for logging, or building PMTK-style outgoing commands).
+//!
+//! Output is written into a caller-provided buffer rather than allocated,
+//! matching the parser's own `no_std`, bounded-length sentence handling.
+
+use core::fmt::Write as _;
+
+use crate::coords::{Hemisphere, Latitude, Longitude};
+use crate::mode::Mode;
+use crate::{Source, RMC, VTG};
+
+/// NMEA 0183's 82-character sentence cap, counting the leading `$` and the
+/// trailing `\r\n`; a sentence within it fits [`crate::Parser`]'s buffer, so
+/// a round-tripped sentence is always re-parseable.
+pub const MAX_ENCODED_LEN: usize = 82;
+
+struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> core::fmt::Write for BufWriter<'a> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let bytes = s.as_bytes();
+        if self.len + bytes.len() > self.buf.len() {
+            return Err(core::fmt::Error);
+        }
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
+
+fn too_long(_: core::fmt::Error) -> &'static str {
+    "Encoded sentence does not fit in buffer!"
+}
+
+fn talker_id(source: Source) -> Result<&'static str, &'static str> {
+    match source {
+        Source::GPS => Ok("GP"),
+        Source::GLONASS => Ok("GL"),
+        Source::GNSS => Ok("GN"),
+        Source::Galileo => Ok("GA"),
+        Source::BeiDou => Ok("GB"),
+        Source::QZSS => Ok("GQ"),
+        Source::MTK => Err("MTK proprietary sentences have no talker-ID encoder."),
+    }
+}
+
+fn mode_char(mode: Mode) -> char {
+    match mode {
+        Mode::Autonomous => 'A',
+        Mode::Differential => 'D',
+        Mode::Estimated => 'E',
+        Mode::Manual => 'M',
+        Mode::Simulated => 'S',
+        Mode::NotValid => 'N',
+        Mode::PreciseFloat => 'F',
+        Mode::FixedRTK => 'R',
+    }
+}
+
+fn write_latitude(w: &mut BufWriter, latitude: Latitude) -> Result<(), &'static str> {
+    let minutes = latitude.minutes as f64 + latitude.seconds / 60.0;
+    let hemisphere = match latitude.hemisphere {
+        Hemisphere::North => 'N',
+        Hemisphere::South => 'S',
+        _ => return Err("Latitude has an east/west hemisphere!"),
+    };
+    write!(w, "{:02}{:07.4},{}", latitude.degrees, minutes, hemisphere).map_err(too_long)
+}
+
+fn write_longitude(w: &mut BufWriter, longitude: Longitude) -> Result<(), &'static str> {
+    let minutes = longitude.minutes as f64 + longitude.seconds / 60.0;
+    let hemisphere = match longitude.hemisphere {
+        Hemisphere::East => 'E',
+        Hemisphere::West => 'W',
+        _ => return Err("Longitude has a north/south hemisphere!"),
+    };
+    write!(w, "{:03}{:07.4},{}", longitude.degrees, minutes, hemisphere).map_err(too_long)
+}
+
+fn checksum(body: &[u8]) -> u8 {
+    body.iter().fold(0u8, |acc, byte| acc ^ byte)
+}
+
+/// Appends the `*HH\r\n` trailer, computing the XOR checksum over the
+/// bytes already written after the leading `$`.
+fn finish(w: &mut BufWriter) -> Result<usize, &'static str> {
+    let sum = checksum(&w.buf[1..w.len]);
+    write!(w, "*{:02X}\r\n", sum).map_err(too_long)?;
+    Ok(w.len)
+}
+
+/// Encodes a `VTG` (course and speed over ground) sentence, returning the
+/// number of bytes written.
+pub fn encode_vtg(vtg: &VTG, buf: &mut [u8]) -> Result<usize, &'static str> {
+    let mut w = BufWriter { buf, len: 0 };
+    write!(w, "${}VTG,", talker_id(vtg.source)?).map_err(too_long)?;
+    match vtg.course {
+        Some(course) => write!(w, "{:.1},T,", course.degrees()).map_err(too_long)?,
+        None => write!(w, ",T,").map_err(too_long)?,
+    }
+    match vtg.magnetic {
+        Some(magnetic) => write!(w, "{:.1},M,", magnetic.degrees()).map_err(too_long)?,
+        None => write!(w, ",M,").map_err(too_long)?,
+    }
+    write!(
+        w,
+        "{:.1},N,{:.1},K,{}",
+        vtg.speed.as_knots(),
+        vtg.speed.as_kph(),
+        mode_char(vtg.mode)
+    )
+    .map_err(too_long)?;
+    finish(&mut w)
+}
+
+/// Encodes an `RMC` (position, velocity and time) sentence, returning the
+/// number of bytes written.
+pub fn encode_rmc(rmc: &RMC, buf: &mut [u8]) -> Result<usize, &'static str> {
+    let mut w = BufWriter { buf, len: 0 };
+    let time = rmc.datetime.time;
+    write!(
+        w,
+        "${}RMC,{:02}{:02}{:06.3},A,",
+        talker_id(rmc.source)?,
+        time.hours,
+        time.minutes,
+        time.seconds
+    )
+    .map_err(too_long)?;
+    write_latitude(&mut w, rmc.latitude)?;
+    write!(w, ",").map_err(too_long)?;
+    write_longitude(&mut w, rmc.longitude)?;
+    write!(w, ",{:.2},", rmc.speed.as_knots()).map_err(too_long)?;
+    match rmc.course {
+        Some(course) => write!(w, "{:.2},", course.degrees()).map_err(too_long)?,
+        None => write!(w, ",").map_err(too_long)?,
+    }
+    let date = rmc.datetime.date;
+    write!(w, "{:02}{:02}{:02},", date.day, date.month, date.year % 100).map_err(too_long)?;
+    match (rmc.course, rmc.magnetic) {
+        (Some(course), Some(magnetic)) => {
+            let variation = course.degrees() - magnetic.degrees();
+            write!(
+                w,
+                "{:.1},{}",
+                variation.abs(),
+                if variation < 0.0 { "W" } else { "E" }
+            )
+            .map_err(too_long)?
+        }
+        _ => write!(w, ",").map_err(too_long)?,
+    }
+    write!(w, ",{}", mode_char(rmc.mode)).map_err(too_long)?;
+    finish(&mut w)
+}
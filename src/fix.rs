@@ -0,0 +1,348 @@
+//! Fuses the individual sentences a receiver emits each epoch (RMC, GGA,
+//! GSA, VTG, ZDA) into a single rolling position/velocity/accuracy
+//! snapshot, so callers don't have to stitch sentence types together by
+//! hand.
+
+use crate::coords::{Altitude, Course, Latitude, Longitude, Speed};
+use crate::datetime::{Date, DateTime, Time};
+use crate::gsa::FixType;
+use crate::{ParseResult, Source};
+
+const MAX_FIX_SATELLITES: usize = 12;
+
+/// A single rolling PVT (position/velocity/time) snapshot, assembled from
+/// whichever sentences have been seen so far.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Fix {
+    /// Navigational system the last update came from.
+    pub source: Source,
+    /// Current date and time in UTC, if a date-bearing sentence (RMC/ZDA)
+    /// has been seen.
+    pub datetime: Option<DateTime>,
+    /// Latitude of the fix.
+    pub latitude: Option<Latitude>,
+    /// Longitude of the fix.
+    pub longitude: Option<Longitude>,
+    /// Altitude above mean sea level.
+    pub altitude: Option<Altitude>,
+    /// Speed over ground.
+    pub speed: Option<Speed>,
+    /// True course over ground, in degrees.
+    pub course: Option<Course>,
+    /// Fix quality indicator from GGA.
+    pub gps_quality: Option<crate::GPSQuality>,
+    /// Number of satellites used in the fix, from GGA.
+    pub sat_in_use: Option<u8>,
+    /// Fix dimensionality from GSA.
+    pub fix_type: Option<FixType>,
+    /// Position dilution of precision.
+    pub pdop: Option<f32>,
+    /// Horizontal dilution of precision.
+    pub hdop: Option<f32>,
+    /// Vertical dilution of precision.
+    pub vdop: Option<f32>,
+    fix_satellites_prn: [u8; MAX_FIX_SATELLITES],
+    fix_satellites_count: usize,
+    epochs: FieldEpochs,
+}
+
+impl Fix {
+    /// Returns the PRNs of the satellites used in the fix, from the most
+    /// recently seen GSA sentence.
+    pub fn fix_satellites_prn(&self) -> &[u8] {
+        &self.fix_satellites_prn[..self.fix_satellites_count]
+    }
+
+    /// The epoch in which a given field group was last refreshed, or `None`
+    /// if that group has never been populated. The epoch count advances
+    /// each time a sentence kind repeats (see `FixState::update()`).
+    pub fn epoch_of(&self, field: FixField) -> Option<u64> {
+        self.epochs.get(field)
+    }
+
+    /// Whether every field group present in this snapshot was last
+    /// refreshed in the same epoch, i.e. the snapshot
+    /// reflects one receiver epoch rather than a stitched-together mix of
+    /// older and newer sentences.
+    pub fn is_single_epoch(&self) -> bool {
+        let mut present = FixField::ALL.iter().filter_map(|&field| self.epoch_of(field));
+        match present.next() {
+            Some(first) => present.all(|epoch| epoch == first),
+            None => true,
+        }
+    }
+}
+
+/// Which field group of a [`Fix`]/[`FixState`] a given epoch belongs to.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FixField {
+    DateTime,
+    Position,
+    Altitude,
+    Velocity,
+    GpsQuality,
+    FixGeometry,
+}
+
+impl FixField {
+    const ALL: [FixField; 6] = [
+        FixField::DateTime,
+        FixField::Position,
+        FixField::Altitude,
+        FixField::Velocity,
+        FixField::GpsQuality,
+        FixField::FixGeometry,
+    ];
+}
+
+/// Per-field-group epochs, tracking which receiver epoch (see
+/// [`SentenceKind`]) last touched each group so a caller can tell whether a
+/// solution mixes data from different epochs.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+struct FieldEpochs {
+    datetime: Option<u64>,
+    position: Option<u64>,
+    altitude: Option<u64>,
+    velocity: Option<u64>,
+    gps_quality: Option<u64>,
+    fix_geometry: Option<u64>,
+}
+
+impl FieldEpochs {
+    fn get(&self, field: FixField) -> Option<u64> {
+        match field {
+            FixField::DateTime => self.datetime,
+            FixField::Position => self.position,
+            FixField::Altitude => self.altitude,
+            FixField::Velocity => self.velocity,
+            FixField::GpsQuality => self.gps_quality,
+            FixField::FixGeometry => self.fix_geometry,
+        }
+    }
+}
+
+/// The sentence types that carry fix data, i.e. the ones a receiver cycles
+/// through once per epoch. `ParseResult` variants with no payload (cold
+/// start) and unrelated sentences (GLL, GSV, PMTK) don't mark an epoch
+/// boundary.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum SentenceKind {
+    Rmc,
+    Gga,
+    Vtg,
+    Gsa,
+    Zda,
+}
+
+impl SentenceKind {
+    fn of(result: &ParseResult) -> Option<SentenceKind> {
+        match result {
+            ParseResult::RMC(Some(_)) => Some(SentenceKind::Rmc),
+            ParseResult::GGA(Some(_)) => Some(SentenceKind::Gga),
+            ParseResult::VTG(Some(_)) => Some(SentenceKind::Vtg),
+            ParseResult::GSA(Some(_)) => Some(SentenceKind::Gsa),
+            ParseResult::ZDA(Some(_)) => Some(SentenceKind::Zda),
+            _ => None,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Which sentence kinds have already contributed to the current epoch. A
+/// receiver normally emits each kind at most once per fix cycle, so seeing
+/// a kind a second time means a new epoch has begun.
+#[derive(Debug, Clone, Copy, Default)]
+struct SeenThisEpoch(u8);
+
+impl SeenThisEpoch {
+    fn contains(self, kind: SentenceKind) -> bool {
+        self.0 & kind.bit() != 0
+    }
+
+    fn insert(&mut self, kind: SentenceKind) {
+        self.0 |= kind.bit();
+    }
+}
+
+/// Accumulates successive [`ParseResult`]s into a rolling [`Fix`],
+/// tracking per-field-group epochs so a caller can tell whether the
+/// snapshot mixes data from different epochs.
+#[derive(Debug, Clone)]
+pub struct FixState {
+    source: Option<Source>,
+    date: Option<Date>,
+    time: Option<Time>,
+    latitude: Option<Latitude>,
+    longitude: Option<Longitude>,
+    altitude: Option<Altitude>,
+    speed: Option<Speed>,
+    course: Option<Course>,
+    gps_quality: Option<crate::GPSQuality>,
+    sat_in_use: Option<u8>,
+    fix_type: Option<FixType>,
+    pdop: Option<f32>,
+    hdop: Option<f32>,
+    vdop: Option<f32>,
+    fix_satellites_prn: [u8; MAX_FIX_SATELLITES],
+    fix_satellites_count: usize,
+    epochs: FieldEpochs,
+    current_epoch: u64,
+    seen_this_epoch: SeenThisEpoch,
+    last_updated: Option<FixField>,
+}
+
+impl FixState {
+    pub fn new() -> Self {
+        FixState {
+            source: None,
+            date: None,
+            time: None,
+            latitude: None,
+            longitude: None,
+            altitude: None,
+            speed: None,
+            course: None,
+            gps_quality: None,
+            sat_in_use: None,
+            fix_type: None,
+            pdop: None,
+            hdop: None,
+            vdop: None,
+            fix_satellites_prn: [0u8; MAX_FIX_SATELLITES],
+            fix_satellites_count: 0,
+            epochs: FieldEpochs::default(),
+            current_epoch: 0,
+            seen_this_epoch: SeenThisEpoch::default(),
+            last_updated: None,
+        }
+    }
+
+    /// Folds one parsed sentence into the rolling solution. Sentences with
+    /// no usable data (`None` payload) are ignored.
+    ///
+    /// A receiver emits each fix-bearing sentence kind at most once per
+    /// epoch, so seeing a kind repeat (e.g. a second RMC) means a new epoch
+    /// has begun; everything folded in before that point belongs to the
+    /// epoch that just closed.
+    pub fn update(&mut self, result: ParseResult) {
+        if let Some(kind) = SentenceKind::of(&result) {
+            if self.seen_this_epoch.contains(kind) {
+                self.current_epoch += 1;
+                self.seen_this_epoch = SeenThisEpoch::default();
+            }
+            self.seen_this_epoch.insert(kind);
+        }
+        match result {
+            ParseResult::RMC(Some(rmc)) => {
+                self.source = Some(rmc.source);
+                self.date = Some(rmc.datetime.date);
+                self.time = Some(rmc.datetime.time);
+                self.epochs.datetime = Some(self.current_epoch);
+                self.latitude = Some(rmc.latitude);
+                self.longitude = Some(rmc.longitude);
+                self.epochs.position = Some(self.current_epoch);
+                self.speed = Some(rmc.speed);
+                self.course = rmc.course;
+                self.epochs.velocity = Some(self.current_epoch);
+                self.last_updated = Some(FixField::Position);
+            }
+            ParseResult::GGA(Some(gga)) => {
+                self.source = Some(gga.source);
+                self.time = Some(gga.time);
+                self.epochs.datetime = Some(self.current_epoch);
+                self.latitude = Some(gga.latitude);
+                self.longitude = Some(gga.longitude);
+                self.epochs.position = Some(self.current_epoch);
+                self.altitude = gga.altitude;
+                self.epochs.altitude = Some(self.current_epoch);
+                self.gps_quality = Some(gga.gps_quality);
+                self.sat_in_use = Some(gga.sat_in_use);
+                self.epochs.gps_quality = Some(self.current_epoch);
+                self.last_updated = Some(FixField::GpsQuality);
+            }
+            ParseResult::VTG(Some(vtg)) => {
+                self.source = Some(vtg.source);
+                self.speed = Some(vtg.speed);
+                self.course = vtg.course;
+                self.epochs.velocity = Some(self.current_epoch);
+                self.last_updated = Some(FixField::Velocity);
+            }
+            ParseResult::GSA(Some(gsa)) => {
+                self.source = Some(gsa.source);
+                self.fix_type = Some(gsa.fix_type);
+                self.pdop = Some(gsa.pdop);
+                self.hdop = Some(gsa.hdop);
+                self.vdop = Some(gsa.vdop);
+                let prns = gsa.get_fix_satellites_prn();
+                self.fix_satellites_count = prns.len().min(MAX_FIX_SATELLITES);
+                self.fix_satellites_prn[..self.fix_satellites_count]
+                    .copy_from_slice(&prns[..self.fix_satellites_count]);
+                self.epochs.fix_geometry = Some(self.current_epoch);
+                self.last_updated = Some(FixField::FixGeometry);
+            }
+            ParseResult::ZDA(Some(zda)) => {
+                self.source = Some(zda.source);
+                self.time = Some(zda.time);
+                self.date = Some(Date {
+                    day: zda.day,
+                    month: zda.month,
+                    year: zda.year,
+                });
+                self.epochs.datetime = Some(self.current_epoch);
+                self.last_updated = Some(FixField::DateTime);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns a single consistent-as-possible snapshot, or `None` if no
+    /// position (latitude and longitude) has been seen yet.
+    pub fn solution(&self) -> Option<Fix> {
+        let source = self.source?;
+        let latitude = self.latitude?;
+        let longitude = self.longitude?;
+
+        Some(Fix {
+            source,
+            datetime: match (self.date, self.time) {
+                (Some(date), Some(time)) => Some(DateTime { date, time }),
+                _ => None,
+            },
+            latitude: Some(latitude),
+            longitude: Some(longitude),
+            altitude: self.altitude,
+            speed: self.speed,
+            course: self.course,
+            gps_quality: self.gps_quality,
+            sat_in_use: self.sat_in_use,
+            fix_type: self.fix_type,
+            pdop: self.pdop,
+            hdop: self.hdop,
+            vdop: self.vdop,
+            fix_satellites_prn: self.fix_satellites_prn,
+            fix_satellites_count: self.fix_satellites_count,
+            epochs: self.epochs,
+        })
+    }
+
+    /// Which field of the solution was most recently updated.
+    pub fn last_updated(&self) -> Option<FixField> {
+        self.last_updated
+    }
+
+    /// The epoch in which a given field group was last refreshed, or `None`
+    /// if that group has never been populated. The epoch count advances
+    /// each time a sentence kind repeats (see [`FixState::update`]).
+    pub fn epoch_of(&self, field: FixField) -> Option<u64> {
+        self.epochs.get(field)
+    }
+}
+
+impl Default for FixState {
+    fn default() -> Self {
+        FixState::new()
+    }
+}
@@ -0,0 +1,87 @@
+//! Topocentric satellite geometry: elevation and azimuth of a satellite as
+//! seen from an observer, both given in ECEF coordinates.
+//!
+//! Useful for cross-checking a GSV sentence's reported az/el fields against
+//! an ephemeris-derived satellite position, or for building a sky plot from
+//! raw orbital data.
+
+/// A point in Earth-Centered, Earth-Fixed coordinates, in meters.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Point {
+    fn sub(self, other: Point) -> Point {
+        Point {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+
+    fn dot(self, other: Point) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn norm(self) -> f64 {
+        libm::sqrt(self.dot(self))
+    }
+}
+
+// WGS84 ellipsoid parameters.
+const WGS84_SEMI_MAJOR_AXIS_METERS: f64 = 6_378_137.0;
+const WGS84_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Converts a geodetic (WGS84) position to ECEF, in meters.
+pub fn geodetic_to_ecef(lat_deg: f64, lon_deg: f64, alt_meters: f64) -> Point {
+    let lat = lat_deg.to_radians();
+    let lon = lon_deg.to_radians();
+    let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+    let sin_lat = libm::sin(lat);
+    let n = WGS84_SEMI_MAJOR_AXIS_METERS / libm::sqrt(1.0 - e2 * sin_lat * sin_lat);
+
+    Point {
+        x: (n + alt_meters) * libm::cos(lat) * libm::cos(lon),
+        y: (n + alt_meters) * libm::cos(lat) * libm::sin(lon),
+        z: (n * (1.0 - e2) + alt_meters) * sin_lat,
+    }
+}
+
+/// Elevation of `sat` above the horizon as seen from `obs`, in degrees.
+/// Measured from the geocentric `up` direction (`obs` itself), which is
+/// well defined at the poles; the result is `NaN` if `sat == obs`.
+pub fn elevation_deg(sat: Point, obs: Point) -> f64 {
+    let up = obs;
+    let d = sat.sub(obs);
+    let e = libm::acos(up.dot(d) / (up.norm() * d.norm()));
+    90.0 - e.to_degrees()
+}
+
+/// Azimuth from true north of `sat` as seen from `obs`, in degrees
+/// (`0..360`). Degenerate at the poles, where `obs.x == obs.y == 0.0`.
+pub fn azimuth_deg(sat: Point, obs: Point) -> f64 {
+    let north = Point {
+        x: -obs.z * obs.x,
+        y: -obs.z * obs.y,
+        z: obs.x * obs.x + obs.y * obs.y,
+    };
+    let east = Point {
+        x: -obs.y,
+        y: obs.x,
+        z: 0.0,
+    };
+    let d = sat.sub(obs);
+
+    let c = north.dot(d) / (north.norm() * d.norm());
+    let s = east.dot(d) / (east.norm() * d.norm());
+    let azimuth = libm::atan2(s, c).to_degrees();
+
+    if azimuth < 0.0 {
+        azimuth + 360.0
+    } else {
+        azimuth
+    }
+}
@@ -0,0 +1,112 @@
+use crate::coords::{Altitude, Latitude, Longitude};
+use crate::datetime::Time;
+use crate::{common, Source};
+
+/// Fix quality reported by GGA.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GPSQuality {
+    Invalid,
+    GPS,
+    DGPS,
+    PPS,
+    RTK,
+    FloatRTK,
+    Estimated,
+    Manual,
+    Simulation,
+}
+
+impl GPSQuality {
+    fn parse(field: Option<&str>) -> Result<Option<GPSQuality>, &'static str> {
+        match field {
+            None | Some("") => Ok(None),
+            Some("0") => Ok(Some(GPSQuality::Invalid)),
+            Some("1") => Ok(Some(GPSQuality::GPS)),
+            Some("2") => Ok(Some(GPSQuality::DGPS)),
+            Some("3") => Ok(Some(GPSQuality::PPS)),
+            Some("4") => Ok(Some(GPSQuality::RTK)),
+            Some("5") => Ok(Some(GPSQuality::FloatRTK)),
+            Some("6") => Ok(Some(GPSQuality::Estimated)),
+            Some("7") => Ok(Some(GPSQuality::Manual)),
+            Some("8") => Ok(Some(GPSQuality::Simulation)),
+            Some(_) => Err("Unknown GPS quality indicator!"),
+        }
+    }
+}
+
+/// Global Positioning System Fix Data: position, altitude and fix quality.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GGA {
+    /// Navigational system.
+    pub source: Source,
+    /// Current time in UTC.
+    pub time: Time,
+    /// Latitude of the fix.
+    pub latitude: Latitude,
+    /// Longitude of the fix.
+    pub longitude: Longitude,
+    /// Fix quality indicator.
+    pub gps_quality: GPSQuality,
+    /// Number of satellites used in the fix.
+    pub sat_in_use: u8,
+    /// Horizontal dilution of precision.
+    pub hdop: f32,
+    /// Altitude above mean sea level.
+    pub altitude: Option<Altitude>,
+    /// Difference between the WGS84 ellipsoid and mean sea level.
+    pub geoidal_separation: Option<f32>,
+    /// Age of the differential corrections, in seconds.
+    pub age_dgps: Option<f32>,
+    /// ID of the station providing differential corrections.
+    pub dgps_station_id: Option<u16>,
+}
+
+impl GGA {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let time = Time::parse_from_hhmmss(fields.next())?;
+        let raw_latitude = fields.next();
+        let raw_latitude_hemisphere = fields.next();
+        let latitude = Latitude::parse(raw_latitude, raw_latitude_hemisphere)?;
+        let raw_longitude = fields.next();
+        let raw_longitude_hemisphere = fields.next();
+        let longitude = Longitude::parse(raw_longitude, raw_longitude_hemisphere)?;
+        let gps_quality = GPSQuality::parse(fields.next())?;
+        let sat_in_use = common::parse_u8(fields.next())?;
+        let hdop = common::parse_f32(fields.next())?;
+        let altitude_meters = common::parse_f64(fields.next())?;
+        let _altitude_units = fields.next();
+        let geoidal_separation = common::parse_f32(fields.next())?;
+        let _geoidal_separation_units = fields.next();
+        let age_dgps = common::parse_f32(fields.next())?;
+        let dgps_station_id = common::parse_u16(fields.next())?;
+
+        if let (
+            Some(time),
+            Some(latitude),
+            Some(longitude),
+            Some(gps_quality),
+            Some(sat_in_use),
+            Some(hdop),
+        ) = (time, latitude, longitude, gps_quality, sat_in_use, hdop)
+        {
+            Ok(Some(GGA {
+                source,
+                time,
+                latitude,
+                longitude,
+                gps_quality,
+                sat_in_use,
+                hdop,
+                altitude: altitude_meters.map(|meters| Altitude { meters }),
+                geoidal_separation,
+                age_dgps,
+                dgps_station_id,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
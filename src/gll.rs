@@ -0,0 +1,52 @@
+use crate::coords::{Latitude, Longitude};
+use crate::datetime::Time;
+use crate::mode::Mode;
+use crate::Source;
+
+/// Geographic position (latitude/longitude) and time of fix.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GLL {
+    /// Navigational system.
+    pub source: Source,
+    /// Current time in UTC.
+    pub time: Time,
+    /// Latitude of the fix.
+    pub latitude: Latitude,
+    /// Longitude of the fix.
+    pub longitude: Longitude,
+    /// How the fix was obtained.
+    pub mode: Mode,
+}
+
+impl GLL {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let raw_latitude = fields.next();
+        let raw_latitude_hemisphere = fields.next();
+        let latitude = Latitude::parse(raw_latitude, raw_latitude_hemisphere)?;
+        let raw_longitude = fields.next();
+        let raw_longitude_hemisphere = fields.next();
+        let longitude = Longitude::parse(raw_longitude, raw_longitude_hemisphere)?;
+        let time = Time::parse_from_hhmmss(fields.next())?;
+        let status = fields.next();
+        let mode = Mode::parse(fields.next())?;
+
+        if status != Some("A") {
+            return Ok(None);
+        }
+
+        if let (Some(time), Some(latitude), Some(longitude)) = (time, latitude, longitude) {
+            Ok(Some(GLL {
+                source,
+                time,
+                latitude,
+                longitude,
+                mode,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
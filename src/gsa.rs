@@ -0,0 +1,109 @@
+use crate::{common, Source};
+
+const MAX_FIX_SATELLITES: usize = 12;
+
+/// Whether the satellites used in this fix were selected manually or the
+/// receiver chose automatically between 2D/3D. This is GSA's own "Mode 1"
+/// field (`M`/`A`) — a different concept from the FAA mode indicator
+/// reported by RMC/GLL/VTG's `mode` field, which GSA's wire format can
+/// never produce.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SelectionMode {
+    Manual,
+    Automatic,
+}
+
+impl SelectionMode {
+    fn parse(field: Option<&str>) -> Result<Option<SelectionMode>, &'static str> {
+        match field {
+            None | Some("") => Ok(None),
+            Some("M") => Ok(Some(SelectionMode::Manual)),
+            Some("A") => Ok(Some(SelectionMode::Automatic)),
+            Some(_) => Err("Unknown GSA selection mode!"),
+        }
+    }
+}
+
+/// Whether the receiver has no fix, a 2D fix, or a full 3D fix.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FixType {
+    NoFix,
+    Fix2D,
+    Fix3D,
+}
+
+impl FixType {
+    fn parse(field: Option<&str>) -> Result<Option<FixType>, &'static str> {
+        match field {
+            None | Some("") => Ok(None),
+            Some("1") => Ok(Some(FixType::NoFix)),
+            Some("2") => Ok(Some(FixType::Fix2D)),
+            Some("3") => Ok(Some(FixType::Fix3D)),
+            Some(_) => Err("Unknown fix type!"),
+        }
+    }
+}
+
+/// GPS DOP and active satellites: fix dimensionality and accuracy estimate.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GSA {
+    /// Navigational system.
+    pub source: Source,
+    /// Whether satellites were selected manually or automatically.
+    pub mode: SelectionMode,
+    /// Fix dimensionality.
+    pub fix_type: FixType,
+    fix_satellites_prn: [u8; MAX_FIX_SATELLITES],
+    fix_satellites_count: usize,
+    /// Position dilution of precision.
+    pub pdop: f32,
+    /// Horizontal dilution of precision.
+    pub hdop: f32,
+    /// Vertical dilution of precision.
+    pub vdop: f32,
+}
+
+impl GSA {
+    /// Returns the PRNs of the satellites used in this fix.
+    pub fn get_fix_satellites_prn(&self) -> &[u8] {
+        &self.fix_satellites_prn[..self.fix_satellites_count]
+    }
+
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let mode = SelectionMode::parse(fields.next())?;
+        let fix_type = FixType::parse(fields.next())?;
+
+        let mut fix_satellites_prn = [0u8; MAX_FIX_SATELLITES];
+        let mut fix_satellites_count = 0;
+        for slot in fix_satellites_prn.iter_mut() {
+            if let Some(prn) = common::parse_u8(fields.next())? {
+                *slot = prn;
+                fix_satellites_count += 1;
+            }
+        }
+
+        let pdop = common::parse_f32(fields.next())?;
+        let hdop = common::parse_f32(fields.next())?;
+        let vdop = common::parse_f32(fields.next())?;
+
+        if let (Some(mode), Some(fix_type), Some(pdop), Some(hdop), Some(vdop)) =
+            (mode, fix_type, pdop, hdop, vdop)
+        {
+            Ok(Some(GSA {
+                source,
+                mode,
+                fix_type,
+                fix_satellites_prn,
+                fix_satellites_count,
+                pdop,
+                hdop,
+                vdop,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::satellite::Satellite;
+use crate::{common, Source};
+
+const MAX_SATELLITES_PER_MESSAGE: usize = 4;
+
+/// Satellites in view, as reported by one message of a (possibly
+/// multi-message) GSV sequence.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GSV {
+    /// Navigational system.
+    pub source: Source,
+    /// Total number of messages in this sequence.
+    pub total_messages_number: u8,
+    /// This message's position within the sequence, starting at 1.
+    pub message_number: u8,
+    /// Total number of satellites in view, across the whole sequence.
+    pub sat_in_view: u8,
+    satellites: [Satellite; MAX_SATELLITES_PER_MESSAGE],
+    satellites_in_message: usize,
+}
+
+const EMPTY_SATELLITE: Satellite = Satellite {
+    prn: 0,
+    elevation: 0,
+    azimuth: 0,
+    snr: None,
+    source: None,
+};
+
+impl GSV {
+    /// Returns the (up to 4) satellites carried by this message.
+    pub fn get_in_view_satellites(&self) -> &[Satellite] {
+        &self.satellites[..self.satellites_in_message]
+    }
+
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let total_messages_number = common::parse_u8(fields.next())?;
+        let message_number = common::parse_u8(fields.next())?;
+        let sat_in_view = common::parse_u8(fields.next())?;
+
+        let mut satellites = [EMPTY_SATELLITE; MAX_SATELLITES_PER_MESSAGE];
+        let mut satellites_in_message = 0;
+        for slot in satellites.iter_mut() {
+            let prn = common::parse_u16(fields.next())?;
+            let elevation = common::parse_u8(fields.next())?;
+            let azimuth = common::parse_u16(fields.next())?;
+            let snr = common::parse_u8(fields.next())?;
+            if let (Some(prn), Some(elevation), Some(azimuth)) = (prn, elevation, azimuth) {
+                *slot = Satellite {
+                    prn,
+                    elevation,
+                    azimuth,
+                    snr,
+                    source: Source::from_gsv_prn(prn),
+                };
+                satellites_in_message += 1;
+            }
+        }
+
+        if let (Some(total_messages_number), Some(message_number), Some(sat_in_view)) =
+            (total_messages_number, message_number, sat_in_view)
+        {
+            Ok(Some(GSV {
+                source,
+                total_messages_number,
+                message_number,
+                sat_in_view,
+                satellites,
+                satellites_in_message,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Reassembles the multi-message `GSV` sequences a receiver sends for each
+//! constellation into one consolidated satellites-in-view list per epoch,
+//! so callers building a sky plot or SNR table don't have to track message
+//! indices themselves.
+
+use crate::satellite::Satellite;
+use crate::{Source, GSV};
+
+/// Upper bound on satellites tracked for a single constellation's
+/// in-progress sequence (8 messages * 4 satellites/message).
+const MAX_SATELLITES_IN_VIEW: usize = 32;
+
+/// How many constellations can have an in-progress GSV sequence at once.
+/// One slot per GSV-capable [`Source`]: GPS, GLONASS, GNSS, Galileo,
+/// BeiDou and QZSS.
+const MAX_CONCURRENT_SEQUENCES: usize = 6;
+
+struct InProgress {
+    source: Source,
+    total_messages_number: u8,
+    next_message_number: u8,
+    satellites: [Satellite; MAX_SATELLITES_IN_VIEW],
+    count: usize,
+}
+
+/// A completed, consolidated view of every satellite one constellation
+/// reported across its GSV sequence.
+#[derive(Debug, Clone)]
+pub struct SatellitesInView {
+    source: Source,
+    satellites: [Satellite; MAX_SATELLITES_IN_VIEW],
+    count: usize,
+}
+
+impl SatellitesInView {
+    pub fn source(&self) -> Source {
+        self.source
+    }
+
+    pub fn satellites(&self) -> &[Satellite] {
+        &self.satellites[..self.count]
+    }
+}
+
+const EMPTY_SATELLITE: Satellite = Satellite {
+    prn: 0,
+    elevation: 0,
+    azimuth: 0,
+    snr: None,
+    source: None,
+};
+
+/// Buffers successive `GSV` sentences, keyed by [`Source`], and yields a
+/// consolidated [`SatellitesInView`] once a sequence's last message
+/// (`message_number == total_messages_number`) arrives.
+pub struct GsvCollector {
+    sequences: [Option<InProgress>; MAX_CONCURRENT_SEQUENCES],
+}
+
+impl GsvCollector {
+    pub fn new() -> Self {
+        GsvCollector {
+            sequences: [None, None, None, None, None, None],
+        }
+    }
+
+    fn slot_for(&mut self, source: Source) -> Option<usize> {
+        if let Some(index) = self
+            .sequences
+            .iter()
+            .position(|slot| matches!(slot, Some(seq) if seq.source == source))
+        {
+            return Some(index);
+        }
+        self.sequences.iter().position(|slot| slot.is_none())
+    }
+
+    /// Folds one `GSV` message into its constellation's in-progress
+    /// sequence. A message numbered `1` always starts a fresh sequence,
+    /// discarding whatever was previously buffered for that constellation
+    /// (covering both a restarted sequence and a partial one that never
+    /// completed). Returns the consolidated view once the sequence's last
+    /// message has been folded in.
+    pub fn update(&mut self, gsv: &GSV) -> Option<SatellitesInView> {
+        let index = self.slot_for(gsv.source)?;
+
+        if gsv.message_number == 1 || self.sequences[index].is_none() {
+            self.sequences[index] = Some(InProgress {
+                source: gsv.source,
+                total_messages_number: gsv.total_messages_number,
+                next_message_number: 1,
+                satellites: [EMPTY_SATELLITE; MAX_SATELLITES_IN_VIEW],
+                count: 0,
+            });
+        }
+
+        let sequence = self.sequences[index].as_mut()?;
+
+        if gsv.message_number != sequence.next_message_number
+            || gsv.source != sequence.source
+            || gsv.total_messages_number != sequence.total_messages_number
+        {
+            // Out-of-order or mismatched message: drop the stale buffer and
+            // wait for the sequence to restart from message 1.
+            self.sequences[index] = None;
+            return None;
+        }
+
+        for satellite in gsv.get_in_view_satellites() {
+            if sequence.count < MAX_SATELLITES_IN_VIEW {
+                sequence.satellites[sequence.count] = *satellite;
+                sequence.count += 1;
+            }
+        }
+        sequence.next_message_number += 1;
+
+        if gsv.message_number == gsv.total_messages_number {
+            let sequence = self.sequences[index].take()?;
+            Some(SatellitesInView {
+                source: sequence.source,
+                satellites: sequence.satellites,
+                count: sequence.count,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for GsvCollector {
+    fn default() -> Self {
+        GsvCollector::new()
+    }
+}
@@ -0,0 +1,247 @@
+//! A `no_std` streaming parser for NMEA 0183 sentences.
+//!
+//! Feed raw bytes in (one at a time, or in chunks) via [`Parser`] and get
+//! back a [`ParseResult`] per recognized sentence.
+
+#![no_std]
+
+pub mod coords;
+pub mod datetime;
+pub mod geometry;
+pub mod satellite;
+
+#[cfg(feature = "chrono")]
+mod chrono_conversions;
+mod common;
+pub mod encode;
+mod fix;
+mod gga;
+mod gll;
+mod gsa;
+mod gsv;
+mod gsv_collector;
+mod mode;
+mod pmtkspf;
+mod rmc;
+mod vtg;
+mod zda;
+
+#[cfg(feature = "chrono")]
+pub use chrono_conversions::to_fixed_offset_datetime;
+pub use fix::{Fix, FixField, FixState};
+pub use gga::{GGA, GPSQuality};
+pub use gll::GLL;
+pub use gsa::{FixType, SelectionMode, GSA};
+pub use gsv::GSV;
+pub use gsv_collector::{GsvCollector, SatellitesInView};
+pub use mode::Mode;
+pub use pmtkspf::{JammingStatus, PMTKSPF};
+pub use rmc::RMC;
+pub use vtg::VTG;
+pub use zda::ZDA;
+
+// NMEA 0183 caps a sentence at 82 characters including the leading `$` and
+// trailing `<CR><LF>`; the buffer only has to hold what's in between.
+#[cfg(feature = "strict")]
+const MAX_SENTENCE_LEN: usize = 79;
+#[cfg(not(feature = "strict"))]
+const MAX_SENTENCE_LEN: usize = 120;
+
+/// Which navigational system (talker ID) a sentence came from.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Source {
+    GPS,
+    GLONASS,
+    GNSS,
+    Galileo,
+    BeiDou,
+    QZSS,
+    MTK,
+}
+
+impl Source {
+    fn from_talker_id(talker_id: &str) -> Result<Source, &'static str> {
+        match talker_id {
+            "GP" => Ok(Source::GPS),
+            "GL" => Ok(Source::GLONASS),
+            "GN" => Ok(Source::GNSS),
+            "GA" => Ok(Source::Galileo),
+            "GB" | "BD" => Ok(Source::BeiDou),
+            "GQ" => Ok(Source::QZSS),
+            "PMTK" => Ok(Source::MTK),
+            _ => Err("Source is not supported!"),
+        }
+    }
+
+    /// Attributes a GSV PRN to a constellation, per the PRN ranges each
+    /// system's receivers report satellites in when pooled into a `GN`
+    /// (multi-constellation) GSV sequence.
+    pub fn from_gsv_prn(prn: u16) -> Option<Source> {
+        match prn {
+            1..=32 => Some(Source::GPS),
+            65..=96 => Some(Source::GLONASS),
+            193..=197 => Some(Source::QZSS),
+            201..=237 => Some(Source::BeiDou),
+            301..=336 => Some(Source::Galileo),
+            _ => None,
+        }
+    }
+}
+
+/// One parsed sentence, still wrapped in `Option` because a recognized
+/// sentence can legitimately carry no usable fix data yet (e.g. cold start).
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseResult {
+    GGA(Option<GGA>),
+    GLL(Option<GLL>),
+    GSA(Option<GSA>),
+    GSV(Option<GSV>),
+    RMC(Option<RMC>),
+    VTG(Option<VTG>),
+    ZDA(Option<ZDA>),
+    PMTK(Option<PMTKSPF>),
+}
+
+fn checksum(sentence: &[u8]) -> u8 {
+    sentence.iter().fold(0u8, |acc, byte| acc ^ byte)
+}
+
+fn dispatch(source: Source, sentence_id: &str, fields: &mut core::str::Split<char>) -> Result<ParseResult, &'static str> {
+    match sentence_id {
+        "GGA" => GGA::parse(source, fields).map(ParseResult::GGA),
+        "GLL" => GLL::parse(source, fields).map(ParseResult::GLL),
+        "GSA" => GSA::parse(source, fields).map(ParseResult::GSA),
+        "GSV" => GSV::parse(source, fields).map(ParseResult::GSV),
+        "RMC" => RMC::parse(source, fields).map(ParseResult::RMC),
+        "VTG" => VTG::parse(source, fields).map(ParseResult::VTG),
+        "ZDA" => ZDA::parse(source, fields).map(ParseResult::ZDA),
+        "SPF" => PMTKSPF::parse(source, fields).map(ParseResult::PMTK),
+        _ => Err("Unsupported sentence type."),
+    }
+}
+
+fn parse_sentence(sentence: &str) -> Result<ParseResult, &'static str> {
+    let (body, checksum_str) = sentence
+        .split_once('*')
+        .ok_or("Sentence is missing a checksum!")?;
+    let expected = u8::from_str_radix(checksum_str, 16).map_err(|_| "Malformed checksum!")?;
+    if checksum(body.as_bytes()) != expected {
+        return Err("Checksum does not match!");
+    }
+
+    let (header, rest) = body.split_once(',').ok_or("Sentence is missing fields!")?;
+    let mut fields = rest.split(',');
+
+    if let Some(talker_id) = header.strip_prefix("PMTK") {
+        let source = Source::from_talker_id("PMTK")?;
+        return dispatch(source, talker_id, &mut fields);
+    }
+
+    if header.len() < 5 {
+        return Err("Unsupported sentence type.");
+    }
+    let (talker_id, sentence_id) = header.split_at(2);
+    let source = Source::from_talker_id(talker_id)?;
+    dispatch(source, sentence_id, &mut fields)
+}
+
+/// Streaming NMEA 0183 parser: feed it raw bytes, get back one
+/// [`ParseResult`] per complete, checksum-valid sentence.
+pub struct Parser {
+    buffer: [u8; MAX_SENTENCE_LEN],
+    len: usize,
+    in_sentence: bool,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Parser {
+            buffer: [0u8; MAX_SENTENCE_LEN],
+            len: 0,
+            in_sentence: false,
+        }
+    }
+
+    /// Feeds a single byte into the parser, returning `Some(result)` once a
+    /// full sentence (terminated by `\r\n`) has been accumulated.
+    pub fn parse_from_byte(&mut self, byte: u8) -> Option<Result<ParseResult, &'static str>> {
+        if byte == b'$' {
+            self.len = 0;
+            self.in_sentence = true;
+            return None;
+        }
+
+        if !self.in_sentence {
+            return None;
+        }
+
+        if byte == b'\n' {
+            self.in_sentence = false;
+            let end = if self.len > 0 && self.buffer[self.len - 1] == b'\r' {
+                self.len - 1
+            } else {
+                self.len
+            };
+            let result = match core::str::from_utf8(&self.buffer[..end]) {
+                Ok(sentence) => parse_sentence(sentence),
+                Err(_) => Err("Sentence is not valid UTF-8!"),
+            };
+            self.len = 0;
+            return Some(result);
+        }
+
+        if self.len >= self.buffer.len() {
+            if byte == b'\r' {
+                // A sentence that fills the buffer may still be followed by
+                // its `\r`: it is not stored, and the `\n` ends the sentence.
+                return None;
+            }
+            self.in_sentence = false;
+            self.len = 0;
+            return Some(Err("NMEA sentence is too long!"));
+        }
+
+        self.buffer[self.len] = byte;
+        self.len += 1;
+        None
+    }
+
+    /// Feeds a slice of bytes, returning an iterator over the
+    /// [`ParseResult`]s completed along the way.
+    pub fn parse_from_bytes<'a>(&'a mut self, bytes: &'a [u8]) -> ParserIterator<'a> {
+        ParserIterator {
+            parser: self,
+            bytes,
+            position: 0,
+        }
+    }
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Parser::new()
+    }
+}
+
+/// Iterator over the sentences completed while feeding a byte slice to
+/// [`Parser::parse_from_bytes`].
+pub struct ParserIterator<'a> {
+    parser: &'a mut Parser,
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Iterator for ParserIterator<'a> {
+    type Item = Result<ParseResult, &'static str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < self.bytes.len() {
+            let byte = self.bytes[self.position];
+            self.position += 1;
+            if let Some(result) = self.parser.parse_from_byte(byte) {
+                return Some(result);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,40 @@
+//! FAA mode indicator, appended to RMC/GLL/VTG since NMEA 2.3.
+
+/// How the fix backing a sentence was obtained.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Mode {
+    /// `A`: a real autonomous GPS fix.
+    Autonomous,
+    /// `D`: a differentially corrected fix.
+    Differential,
+    /// `E`: dead-reckoning / estimated position, no fix.
+    Estimated,
+    /// `M`: manually entered position.
+    Manual,
+    /// `S`: simulated, e.g. from a test set or simulator mode.
+    Simulated,
+    /// `N`: the receiver has no usable data.
+    NotValid,
+    /// `F`: an RTK fix with float ambiguity resolution.
+    PreciseFloat,
+    /// `R`: an RTK fix with fixed ambiguity resolution.
+    FixedRTK,
+}
+
+impl Mode {
+    /// Parses the single-character FAA mode indicator. A missing field (pre
+    /// NMEA 2.3 receivers never send it) defaults to `Autonomous`.
+    pub(crate) fn parse(field: Option<&str>) -> Result<Mode, &'static str> {
+        match field {
+            None | Some("") | Some("A") => Ok(Mode::Autonomous),
+            Some("D") => Ok(Mode::Differential),
+            Some("E") => Ok(Mode::Estimated),
+            Some("M") => Ok(Mode::Manual),
+            Some("S") => Ok(Mode::Simulated),
+            Some("N") => Ok(Mode::NotValid),
+            Some("F") => Ok(Mode::PreciseFloat),
+            Some("R") => Ok(Mode::FixedRTK),
+            Some(_) => Err("Unknown mode indicator!"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::Source;
+
+/// Jamming detector state reported by MediaTek's proprietary `PMTKSPF`
+/// sentence.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum JammingStatus {
+    None,
+    Warning,
+    Critical,
+}
+
+impl JammingStatus {
+    fn parse(field: Option<&str>) -> Result<Option<JammingStatus>, &'static str> {
+        match field {
+            None | Some("") => Ok(None),
+            Some("0") => Ok(Some(JammingStatus::None)),
+            Some("2") => Ok(Some(JammingStatus::Warning)),
+            Some("3") => Ok(Some(JammingStatus::Critical)),
+            Some(_) => Err("Unknown jamming status!"),
+        }
+    }
+}
+
+/// MediaTek proprietary jamming status report.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PMTKSPF {
+    /// Navigational system (always `MTK` for this proprietary sentence).
+    pub source: Source,
+    /// Jamming detector state.
+    pub jamming_status: JammingStatus,
+}
+
+impl PMTKSPF {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let jamming_status = JammingStatus::parse(fields.next())?;
+
+        if let Some(jamming_status) = jamming_status {
+            Ok(Some(PMTKSPF {
+                source,
+                jamming_status,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
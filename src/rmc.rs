@@ -0,0 +1,80 @@
+use crate::coords::{Course, Latitude, Longitude, Speed};
+use crate::datetime::{Date, DateTime, Time};
+use crate::mode::Mode;
+use crate::{common, Source};
+
+/// Recommended minimum specific GPS/Transit data: the primary position,
+/// velocity and time sentence.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RMC {
+    /// Navigational system.
+    pub source: Source,
+    /// Current date and time in UTC.
+    pub datetime: DateTime,
+    /// Latitude of the fix.
+    pub latitude: Latitude,
+    /// Longitude of the fix.
+    pub longitude: Longitude,
+    /// Speed over ground.
+    pub speed: Speed,
+    /// True course over ground.
+    pub course: Option<Course>,
+    /// Magnetic course over ground, derived from the true course and the
+    /// reported magnetic variation.
+    pub magnetic: Option<Course>,
+    /// How the fix was obtained.
+    pub mode: Mode,
+}
+
+impl RMC {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let time = Time::parse_from_hhmmss(fields.next())?;
+        let status = fields.next();
+        let raw_latitude = fields.next();
+        let raw_latitude_hemisphere = fields.next();
+        let latitude = Latitude::parse(raw_latitude, raw_latitude_hemisphere)?;
+        let raw_longitude = fields.next();
+        let raw_longitude_hemisphere = fields.next();
+        let longitude = Longitude::parse(raw_longitude, raw_longitude_hemisphere)?;
+        let speed = common::parse_f32(fields.next())?;
+        let course = common::parse_f32(fields.next())?;
+        let date = Date::parse_from_ddmmyy(fields.next())?;
+        let magnetic = common::parse_f32(fields.next())?;
+        let magnetic_direction = fields.next();
+        let mode = Mode::parse(fields.next())?;
+
+        if status != Some("A") {
+            return Ok(None);
+        }
+
+        let variation = match (magnetic, magnetic_direction) {
+            (Some(variation), Some("W")) => Some(-variation),
+            (Some(variation), _) => Some(variation),
+            (None, _) => None,
+        };
+        let magnetic = match (course, variation) {
+            (Some(course), Some(variation)) => Some(Course::from(course - variation)),
+            _ => None,
+        };
+
+        if let (Some(time), Some(date), Some(latitude), Some(longitude), Some(speed)) =
+            (time, date, latitude, longitude, speed)
+        {
+            Ok(Some(RMC {
+                source,
+                datetime: DateTime { date, time },
+                latitude,
+                longitude,
+                speed: Speed::from_knots(speed),
+                course: course.map(Course::from),
+                magnetic,
+                mode,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
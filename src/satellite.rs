@@ -0,0 +1,21 @@
+//! A single satellite's sky position and signal quality, as reported by GSV.
+
+use crate::Source;
+
+/// One satellite entry from a GSV sentence.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Satellite {
+    /// PRN (pseudo-random noise) number identifying the satellite. Ranges
+    /// up to 336 for Galileo, so this doesn't fit in a `u8`.
+    pub prn: u16,
+    /// Elevation above the horizon, in degrees (0-90).
+    pub elevation: u8,
+    /// Azimuth, in degrees from true north (0-359).
+    pub azimuth: u16,
+    /// Signal-to-noise ratio in dB, when the receiver is tracking it.
+    pub snr: Option<u8>,
+    /// Constellation this satellite's PRN belongs to, per
+    /// [`Source::from_gsv_prn`]. Useful when the enclosing `GSV` sentence's
+    /// own `source` is a pooled multi-constellation talker ID (`GN`).
+    pub source: Option<Source>,
+}
@@ -0,0 +1,49 @@
+use crate::coords::{Course, Speed};
+use crate::mode::Mode;
+use crate::{common, Source};
+
+/// Course and speed over ground.
+#[derive(Debug, PartialEq, Clone)]
+pub struct VTG {
+    /// Navigational system.
+    pub source: Source,
+    /// True course over ground.
+    pub course: Option<Course>,
+    /// Magnetic course over ground.
+    pub magnetic: Option<Course>,
+    /// Speed over ground.
+    pub speed: Speed,
+    /// How the fix was obtained.
+    pub mode: Mode,
+}
+
+impl VTG {
+    pub(crate) fn parse<'a>(
+        source: Source,
+        fields: &mut core::str::Split<'a, char>,
+    ) -> Result<Option<Self>, &'static str> {
+        let course = common::parse_f32(fields.next())?;
+        let _course_reference = fields.next();
+        let magnetic = common::parse_f32(fields.next())?;
+        let _magnetic_reference = fields.next();
+        let speed_knots = common::parse_f32(fields.next())?;
+        let _speed_knots_unit = fields.next();
+        let speed_kph = common::parse_f32(fields.next())?;
+        let _speed_kph_unit = fields.next();
+        let mode = Mode::parse(fields.next())?;
+
+        let speed = match (speed_knots, speed_kph) {
+            (Some(knots), _) => Speed::from_knots(knots),
+            (None, Some(kph)) => Speed::from_knots(kph / 1.852),
+            (None, None) => return Ok(None),
+        };
+
+        Ok(Some(VTG {
+            source,
+            course: course.map(Course::from),
+            magnetic: magnetic.map(Course::from),
+            speed,
+            mode,
+        }))
+    }
+}
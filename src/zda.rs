@@ -1,4 +1,4 @@
-use crate::datetime::{Date, Time};
+use crate::datetime::Time;
 use crate::{common, Source};
 
 /// Geographic latitude ang longitude sentence with time of fix and receiver state.
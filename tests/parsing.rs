@@ -4,16 +4,21 @@ use nmea0183::coords::Hemisphere;
 use nmea0183::coords::Latitude;
 use nmea0183::coords::Longitude;
 use nmea0183::datetime;
+use nmea0183::geometry;
 use nmea0183::satellite;
 use nmea0183::FixType;
 use nmea0183::GPSQuality;
 use nmea0183::JammingStatus;
 use nmea0183::Mode;
+use nmea0183::SelectionMode;
 use nmea0183::GGA;
 use nmea0183::GLL;
 use nmea0183::PMTKSPF;
 use nmea0183::RMC;
 use nmea0183::VTG;
+use nmea0183::encode;
+use nmea0183::GsvCollector;
+use nmea0183::{Fix, FixField, FixState};
 use nmea0183::{ParseResult, Parser, Source};
 
 #[test]
@@ -252,6 +257,26 @@ fn test_correct_gga_without_altitude() {
     assert!(parsed);
 }
 
+#[test]
+fn test_gga_with_non_ascii_latitude_field_is_rejected_not_panicked() {
+    // A crafted sentence can still pass the XOR checksum while carrying a
+    // multi-byte UTF-8 character inside the latitude field; this must be
+    // rejected as a parse error, not panic on a non-char-boundary slice.
+    let mut p = Parser::new();
+    let sentence = "$GPGGA,145659.00,4é16.45,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*33\r\n"
+        .as_bytes();
+    let mut parsed = false;
+    for b in sentence.iter() {
+        let r = p.parse_from_byte(*b);
+        if r.is_some() {
+            assert_eq!(r.unwrap(), Err("Coordinate field is not ASCII!"));
+            parsed = true;
+            break;
+        }
+    }
+    assert!(parsed);
+}
+
 #[test]
 fn test_correct_rmc2() {
     let mut p = Parser::new();
@@ -338,25 +363,29 @@ fn test_correct_gsv() {
                     prn: 21,
                     elevation: 44,
                     azimuth: 141,
-                    snr: Some(47)
+                    snr: Some(47),
+                    source: Some(Source::GPS)
                 },
                 satellite::Satellite {
                     prn: 15,
                     elevation: 14,
                     azimuth: 49,
-                    snr: Some(44)
+                    snr: Some(44),
+                    source: Some(Source::GPS)
                 },
                 satellite::Satellite {
                     prn: 6,
                     elevation: 31,
                     azimuth: 255,
-                    snr: Some(46)
+                    snr: Some(46),
+                    source: Some(Source::GPS)
                 },
                 satellite::Satellite {
                     prn: 3,
                     elevation: 25,
                     azimuth: 280,
-                    snr: Some(44)
+                    snr: Some(44),
+                    source: Some(Source::GPS)
                 }
             ],
         )
@@ -386,7 +415,8 @@ fn test_correct_gsv2() {
                 prn: 68,
                 elevation: 37,
                 azimuth: 284,
-                snr: Some(50)
+                snr: Some(50),
+                source: Some(Source::GLONASS)
             },],
         )
     }
@@ -420,7 +450,7 @@ fn test_correct_gsa() {
             }
         };
         assert_eq!(gsa.source, Source::GNSS);
-        assert_eq!(gsa.mode, Mode::Autonomous);
+        assert_eq!(gsa.mode, SelectionMode::Automatic);
         assert_eq!(gsa.fix_type, FixType::Fix3D);
         assert_eq!(gsa.get_fix_satellites_prn(), [21, 5, 29, 25, 12, 10, 26, 2]);
         assert_eq!(gsa.pdop, 1.2);
@@ -429,6 +459,22 @@ fn test_correct_gsa() {
     }
 }
 
+#[test]
+fn test_correct_gsa_with_manual_selection_mode() {
+    let mut p = Parser::new();
+    let b = b"$GNGSA,M,3,21,5,29,25,12,10,26,2,,,,,1.2,0.7,1.0*2b\r\n";
+    {
+        let mut iter = p.parse_from_bytes(&b[..]);
+        let gsa = match iter.next().unwrap().unwrap() {
+            ParseResult::GSA(Some(gsa)) => gsa,
+            _ => {
+                panic!("Unexpected ParseResult variant while parsing GSA data.");
+            }
+        };
+        assert_eq!(gsa.mode, SelectionMode::Manual);
+    }
+}
+
 #[test]
 fn test_correct_zda() {
     let mut p = Parser::new();
@@ -558,3 +604,367 @@ fn test_parser_iterator() {
         assert!(iter.next().is_none());
     }
 }
+
+#[test]
+fn test_encode_vtg_roundtrip() {
+    let vtg = VTG {
+        source: Source::GPS,
+        course: Some(From::from(89.0)),
+        magnetic: None,
+        speed: coords::Speed::from_knots(15.2),
+        mode: Mode::Autonomous,
+    };
+    let mut buf = [0u8; encode::MAX_ENCODED_LEN];
+    let len = encode::encode_vtg(&vtg, &mut buf).unwrap();
+
+    let mut p = Parser::new();
+    let mut parsed = false;
+    for b in buf[..len].iter() {
+        if let Some(r) = p.parse_from_byte(*b) {
+            assert_eq!(r, Ok(ParseResult::VTG(Some(vtg.clone()))));
+            parsed = true;
+            break;
+        }
+    }
+    assert!(parsed);
+}
+
+#[test]
+fn test_encode_rmc_roundtrip() {
+    let rmc = RMC {
+        source: Source::GPS,
+        datetime: datetime::DateTime {
+            date: datetime::Date {
+                day: 20,
+                month: 9,
+                year: 2006,
+            },
+            time: datetime::Time {
+                hours: 12,
+                minutes: 55,
+                seconds: 4.049,
+            },
+        },
+        latitude: TryFrom::try_from(55.703981666666664).unwrap(),
+        longitude: TryFrom::try_from(37.69343833333333).unwrap(),
+        speed: coords::Speed::from_knots(0.06),
+        course: Some(From::from(25.82)),
+        magnetic: None,
+        mode: Mode::Autonomous,
+    };
+    let mut buf = [0u8; encode::MAX_ENCODED_LEN];
+    let len = encode::encode_rmc(&rmc, &mut buf).unwrap();
+
+    let mut p = Parser::new();
+    let mut parsed = false;
+    for b in buf[..len].iter() {
+        if let Some(r) = p.parse_from_byte(*b) {
+            assert_eq!(r, Ok(ParseResult::RMC(Some(rmc.clone()))));
+            parsed = true;
+            break;
+        }
+    }
+    assert!(parsed);
+}
+
+#[test]
+fn test_encode_rmc_roundtrip_with_magnetic_variation() {
+    // Exercises the course.degrees() - magnetic.degrees() variation
+    // reconstruction, which test_encode_rmc_roundtrip never touches since
+    // it leaves `magnetic` as None. Degrees/minutes are given directly
+    // (rather than via TryFrom<f64>) with a zero seconds component so the
+    // lat/lon round-trip itself doesn't fall afoul of the unrelated
+    // decompose_degrees precision loss that test_encode_rmc_roundtrip hits.
+    let rmc = RMC {
+        source: Source::GPS,
+        datetime: datetime::DateTime {
+            date: datetime::Date {
+                day: 20,
+                month: 9,
+                year: 2006,
+            },
+            time: datetime::Time {
+                hours: 12,
+                minutes: 55,
+                seconds: 4.049,
+            },
+        },
+        latitude: Latitude {
+            degrees: 55,
+            minutes: 42,
+            seconds: 0.0,
+            hemisphere: Hemisphere::North,
+        },
+        longitude: Longitude {
+            degrees: 37,
+            minutes: 41,
+            seconds: 0.0,
+            hemisphere: Hemisphere::East,
+        },
+        speed: coords::Speed::from_knots(0.06),
+        course: Some(From::from(90.0)),
+        magnetic: Some(From::from(85.5)),
+        mode: Mode::Autonomous,
+    };
+    let mut buf = [0u8; encode::MAX_ENCODED_LEN];
+    let len = encode::encode_rmc(&rmc, &mut buf).unwrap();
+
+    let mut p = Parser::new();
+    let mut parsed = false;
+    for b in buf[..len].iter() {
+        if let Some(r) = p.parse_from_byte(*b) {
+            assert_eq!(r, Ok(ParseResult::RMC(Some(rmc.clone()))));
+            parsed = true;
+            break;
+        }
+    }
+    assert!(parsed);
+}
+
+fn parse_gsv(p: &mut Parser, sentence: &[u8]) -> nmea0183::GSV {
+    let mut iter = p.parse_from_bytes(sentence);
+    match iter.next().unwrap().unwrap() {
+        ParseResult::GSV(Some(gsv)) => gsv,
+        _ => panic!("Unexpected ParseResult variant while parsing GSV data."),
+    }
+}
+
+#[test]
+fn test_gsv_collector_single_message_sequence() {
+    let mut p = Parser::new();
+    let gsv = parse_gsv(
+        &mut p,
+        b"$GPGSV,1,1,04,21,44,141,47,15,14,049,44,6,31,255,46,3,25,280,44*7F\r\n",
+    );
+
+    let mut collector = GsvCollector::new();
+    let view = collector.update(&gsv).expect("sequence completes in one message");
+    assert_eq!(view.source(), Source::GPS);
+    assert_eq!(view.satellites().len(), 4);
+    assert_eq!(view.satellites()[0].prn, 21);
+    assert_eq!(view.satellites()[3].prn, 3);
+}
+
+#[test]
+fn test_gsv_collector_restart_discards_partial_sequence() {
+    let mut p = Parser::new();
+    let mut collector = GsvCollector::new();
+
+    let first = parse_gsv(&mut p, b"$GPGSV,2,1,08,01,10,020,30*43\r\n");
+    assert!(collector.update(&first).is_none());
+
+    // A new message_number == 1 arrives before the sequence completed: the
+    // buffered first satellite must be dropped, not merged in.
+    let restarted = parse_gsv(&mut p, b"$GPGSV,2,1,08,02,11,021,31*41\r\n");
+    assert!(collector.update(&restarted).is_none());
+
+    let last = parse_gsv(&mut p, b"$GPGSV,2,2,08,03,12,022,32*40\r\n");
+    let view = collector.update(&last).expect("sequence completes on message 2");
+
+    assert_eq!(view.satellites().len(), 2);
+    assert_eq!(view.satellites()[0].prn, 2);
+    assert_eq!(view.satellites()[1].prn, 3);
+}
+
+#[test]
+fn test_fix_state_fuses_rmc_and_gsa() {
+    let mut state = FixState::new();
+    assert!(state.solution().is_none());
+
+    let mut p = Parser::new();
+    for result in
+        p.parse_from_bytes(b"$GPRMC,113650.0,A,5548.607,S,03739.387,W,000.01,255.6,210403,08.7,E*66\r\n")
+    {
+        state.update(result.unwrap());
+    }
+    let after_rmc: Fix = state.solution().expect("position seen from RMC");
+    assert_eq!(after_rmc.source, Source::GPS);
+    assert!(after_rmc.latitude.is_some());
+    assert_eq!(state.last_updated(), Some(FixField::Position));
+    let position_epoch = after_rmc
+        .epoch_of(FixField::Position)
+        .expect("position epoch recorded");
+    assert!(after_rmc.is_single_epoch());
+
+    let mut p2 = Parser::new();
+    for result in p2.parse_from_bytes(b"$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n") {
+        state.update(result.unwrap());
+    }
+    let after_gsa = state.solution().expect("position still carried over from RMC");
+    assert_eq!(state.last_updated(), Some(FixField::FixGeometry));
+    assert_eq!(after_gsa.epoch_of(FixField::Position), Some(position_epoch));
+    // RMC and GSA are distinct sentence kinds, neither of which has repeated
+    // yet, so they're still taken to belong to the same receiver cycle.
+    assert_eq!(after_gsa.epoch_of(FixField::FixGeometry), Some(position_epoch));
+    assert!(after_gsa.is_single_epoch());
+
+    // A second RMC means the receiver has moved on to its next cycle: the
+    // position gets a fresh epoch while the GSA-sourced fix geometry is left
+    // behind in the one that just closed.
+    let mut p3 = Parser::new();
+    for result in
+        p3.parse_from_bytes(b"$GPRMC,113651.0,A,5548.607,S,03739.387,W,000.01,255.6,210403,08.7,E*67\r\n")
+    {
+        state.update(result.unwrap());
+    }
+    let after_second_rmc = state.solution().expect("position still present");
+    assert_ne!(
+        after_second_rmc.epoch_of(FixField::Position),
+        Some(position_epoch)
+    );
+    assert_ne!(
+        after_second_rmc.epoch_of(FixField::Position),
+        after_second_rmc.epoch_of(FixField::FixGeometry)
+    );
+    assert!(!after_second_rmc.is_single_epoch());
+}
+
+#[test]
+fn test_fix_state_has_no_solution_before_a_position_sentence() {
+    let mut state = FixState::new();
+    let mut p = Parser::new();
+    for result in p.parse_from_bytes(b"$GPZDA,201530.00,04,07,2002,00,00*60\r\n") {
+        state.update(result.unwrap());
+    }
+    assert!(state.solution().is_none());
+}
+
+#[test]
+fn test_fix_state_epoch_advances_only_when_a_sentence_kind_repeats() {
+    // ZDA and GGA are distinct sentence kinds, so a GGA following a ZDA is
+    // still the same receiver cycle: the `DateTime` group's epoch carries
+    // over even though GGA never touches `date`.
+    let mut state = FixState::new();
+    let mut p = Parser::new();
+    for result in p.parse_from_bytes(b"$GPZDA,201530.00,04,07,2002,00,00*60\r\n") {
+        state.update(result.unwrap());
+    }
+    let datetime_epoch_after_zda = state.epoch_of(FixField::DateTime);
+
+    let mut p2 = Parser::new();
+    for result in
+        p2.parse_from_bytes(b"$GPGGA,145659.00,5956.695396,N,03022.454999,E,2,07,0.6,9.0,M,18.0,M,,*62\r\n")
+    {
+        state.update(result.unwrap());
+    }
+    assert_eq!(state.epoch_of(FixField::DateTime), datetime_epoch_after_zda);
+
+    // A second ZDA means the sentence kind has repeated, so the cycle has
+    // rolled over and the `DateTime` group gets a fresh epoch.
+    let mut p3 = Parser::new();
+    for result in p3.parse_from_bytes(b"$GPZDA,201531.00,04,07,2002,00,00*61\r\n") {
+        state.update(result.unwrap());
+    }
+    assert_ne!(state.epoch_of(FixField::DateTime), datetime_epoch_after_zda);
+}
+
+#[test]
+fn test_mode_parses_full_faa_indicator_set() {
+    let cases = [
+        ("D", Mode::Differential),
+        ("E", Mode::Estimated),
+        ("M", Mode::Manual),
+        ("S", Mode::Simulated),
+        ("N", Mode::NotValid),
+        ("F", Mode::PreciseFloat),
+        ("R", Mode::FixedRTK),
+    ];
+    let checksums = ["59", "58", "50", "4E", "53", "5B", "4F"];
+    for ((mode_char, expected), checksum) in cases.iter().zip(checksums.iter()) {
+        let sentence = format!("$GPGLL,4916.45,N,12311.12,W,225444,A,{mode_char}*{checksum}\r\n");
+        let mut p = Parser::new();
+        let gll = match p.parse_from_bytes(sentence.as_bytes()).next().unwrap().unwrap() {
+            ParseResult::GLL(Some(gll)) => gll,
+            other => panic!("Unexpected ParseResult variant: {other:?}"),
+        };
+        assert_eq!(gll.mode, *expected);
+    }
+}
+
+#[test]
+fn test_source_recognizes_galileo_beidou_and_qzss_talker_ids() {
+    let cases = [
+        ("GA", "34", Source::Galileo),
+        ("GB", "37", Source::BeiDou),
+        ("BD", "34", Source::BeiDou),
+        ("GQ", "24", Source::QZSS),
+    ];
+    for (talker, checksum, expected) in cases {
+        let sentence = format!("${talker}VTG,054.7,T,034.4,M,005.5,N,010.2,K,A*{checksum}\r\n");
+        let mut p = Parser::new();
+        let vtg = match p.parse_from_bytes(sentence.as_bytes()).next().unwrap().unwrap() {
+            ParseResult::VTG(Some(vtg)) => vtg,
+            other => panic!("Unexpected ParseResult variant: {other:?}"),
+        };
+        assert_eq!(vtg.source, expected);
+    }
+}
+
+#[test]
+fn test_gsv_attributes_pooled_prn_to_its_constellation() {
+    // A `GN` (multi-constellation) GSV sentence pools satellites from
+    // several systems into one sequence; each satellite's own PRN range
+    // identifies its actual constellation, independent of the sentence's
+    // talker-derived `source`.
+    let mut p = Parser::new();
+    let gsv = parse_gsv(&mut p, b"$GNGSV,1,1,02,03,44,141,47,310,14,049,44*5B\r\n");
+    let satellites = gsv.get_in_view_satellites();
+    assert_eq!(satellites[0].prn, 3);
+    assert_eq!(satellites[0].source, Some(Source::GPS));
+    assert_eq!(satellites[1].prn, 310);
+    assert_eq!(satellites[1].source, Some(Source::Galileo));
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_to_fixed_offset_datetime_keeps_minutes_sign_when_hours_is_zero() {
+    // A `+00:30` ZDA offset has `offset_hours == 0`, so `i8::signum()`
+    // would give `0` and silently zero out `offset_minutes` instead of
+    // keeping it positive.
+    let datetime = datetime::DateTime {
+        date: datetime::Date {
+            day: 12,
+            month: 9,
+            year: 2018,
+        },
+        time: datetime::Time {
+            hours: 18,
+            minutes: 16,
+            seconds: 4.456,
+        },
+    };
+    let offset = nmea0183::to_fixed_offset_datetime(datetime, 0, 30).unwrap();
+    assert_eq!(offset.offset().local_minus_utc(), 30 * 60);
+}
+
+#[test]
+fn test_geometry_satellite_directly_overhead_has_elevation_90() {
+    // On the equator the ellipsoid normal is radial, so a satellite at the
+    // same lat/lon as the observer is exactly overhead. Off the
+    // equator/poles, WGS84's flattening tilts the normal away from the
+    // position vector, so elevation would only be approximately 90.
+    let obs = geometry::geodetic_to_ecef(0.0, 10.0, 0.0);
+    let sat = geometry::geodetic_to_ecef(0.0, 10.0, 20_000_000.0);
+    let elevation = geometry::elevation_deg(sat, obs);
+    assert!(
+        (elevation - 90.0).abs() < 1e-6,
+        "expected ~90 degrees, got {elevation}"
+    );
+}
+
+#[test]
+fn test_geometry_azimuth_is_degenerate_at_the_poles() {
+    // At a pole, `obs.x == obs.y == 0.0`, so the local east/north basis
+    // used by `azimuth_deg` collapses to a zero-length vector; this is
+    // documented as degenerate and returns `NaN` rather than a silently
+    // wrong bearing. `geodetic_to_ecef(90.0, ..)` only lands within a few
+    // `1e-10` of the pole due to `cos(90deg)` rounding, so the pole itself
+    // is constructed directly here.
+    let obs = geometry::Point {
+        x: 0.0,
+        y: 0.0,
+        z: 6_356_752.314245179,
+    };
+    let sat = geometry::geodetic_to_ecef(45.0, 10.0, 20_000_000.0);
+    assert!(geometry::azimuth_deg(sat, obs).is_nan());
+}